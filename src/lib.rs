@@ -9,7 +9,7 @@ use std::{
     time::SystemTime,
 };
 
-use serde::{Serialize, de::DeserializeOwned};
+use serde::{de::DeserializeOwned, Serialize};
 
 /// Represents an error that can occur in this crate.
 #[derive(Debug, thiserror::Error)]
@@ -65,6 +65,37 @@ pub enum Error {
     /// An error occurred when writing to the cache.
     #[error("can't write cache into: {1}")]
     WriteCache(#[source] serde_json::Error, PathBuf),
+    /// An I/O error occurred while reading a record's raw bytes at an offset.
+    #[error("can't read record at offset {1} from {2}")]
+    ReadRecord(#[source] io::Error, u64, PathBuf),
+    /// An error occurred while (de)compressing the binary cache.
+    #[error("can't zstd-(de)compress cache {1}")]
+    Zstd(#[source] io::Error, PathBuf),
+    /// An error occurred while memory-mapping a file.
+    #[error("can't mmap file: {1}")]
+    Mmap(#[source] io::Error, PathBuf),
+    /// An error occurred while encoding the binary cache.
+    #[error("can't encode binary cache into: {1}")]
+    EncodeCache(#[source] bincode::Error, PathBuf),
+    /// An error occurred while decoding the binary cache.
+    #[error("can't decode binary cache from: {1}")]
+    DecodeCache(#[source] bincode::Error, PathBuf),
+    /// A field's value didn't match its header-declared [`ColumnType`].
+    #[error(
+        "column `{column}` in `{file}` at {offset} doesn't match its declared type {expected:?}: {line}"
+    )]
+    SchemaMismatch {
+        /// The path to the file where the error occurred.
+        file: PathBuf,
+        /// The byte offset in the file where the error occurred.
+        offset: u64,
+        /// The name of the offending column.
+        column: String,
+        /// The column's declared type.
+        expected: ColumnType,
+        /// The content of the line where the error occurred.
+        line: String,
+    },
 }
 
 /// A `Result` alias where the `Err` case is `csv_partial_cache::Error`.
@@ -88,6 +119,52 @@ pub struct CsvPartialCache<T> {
     pub path: PathBuf,
     /// The items in the cache.
     pub items: Box<[T]>,
+    /// Secondary indices built with [`CsvPartialCache::build_index`], looked
+    /// up by the id it returns via [`CsvPartialCache::find_by`].
+    indices: Vec<Box<dyn SecondaryIndex<T>>>,
+    /// The header row parsed into a [`Schema`], if the cache was built with
+    /// `has_headers(true)` (the default).
+    pub schema: Schema,
+    /// The quote character to assume when re-reading a record's raw bytes
+    /// from the file (e.g. in `full_record`), so a quoted field that embeds
+    /// a newline or the delimiter is still read as one record. `None` when
+    /// the cache was built with `quoting(false)`, so fetches split on every
+    /// `\n` exactly like the indexing pass did, instead of disagreeing with
+    /// it over a field that merely contains a stray quote character.
+    quote: Option<u8>,
+}
+
+/// A secondary index over a `CsvPartialCache`'s items, sorted by an
+/// extracted key, type-erased so a cache can hold several of them with
+/// different key types at once.
+trait SecondaryIndex<T>: std::fmt::Debug {
+    /// Binary-searches the index for `key`, returning the matching item's
+    /// position in `items`, or `None` if `key` is the wrong type for this
+    /// index or isn't present.
+    fn find(&self, key: &dyn std::any::Any) -> Option<usize>;
+}
+
+/// A [`SecondaryIndex`] backed by a `(key, item index)` pairs sorted by key.
+struct KeyIndex<K> {
+    entries: Box<[(K, usize)]>,
+}
+
+impl<K> std::fmt::Debug for KeyIndex<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyIndex")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+impl<T, K: Ord + 'static> SecondaryIndex<T> for KeyIndex<K> {
+    fn find(&self, key: &dyn std::any::Any) -> Option<usize> {
+        let key = key.downcast_ref::<K>()?;
+        self.entries
+            .binary_search_by(|(k, _)| k.cmp(key))
+            .ok()
+            .map(|i| self.entries[i].1)
+    }
 }
 
 /// An iterator over lines and their byte offsets.
@@ -184,25 +261,347 @@ where
     }
 }
 
-impl<T> CsvPartialCache<T>
+/// A builder for configuring CSV parse options before indexing a file into a
+/// [`CsvPartialCache`].
+///
+/// By default it drives a [`csv::Reader`] over the file so that quoted
+/// fields containing embedded newlines or the delimiter itself don't throw
+/// off record boundaries. Call [`CsvPartialCacheBuilder::quoting`] with
+/// `false` to fall back to the faster, newline-splitting [`LineOffset`] path
+/// for files that are known not to need RFC-4180 quote handling.
+#[derive(Debug, Clone)]
+pub struct CsvPartialCacheBuilder<T> {
+    delimiter: u8,
+    quote: u8,
+    has_headers: bool,
+    comment: Option<u8>,
+    flexible: bool,
+    quoting: bool,
+    _item: PhantomData<fn() -> T>,
+}
+
+/// The delimiter `CsvPartialCacheBuilder` assumes by default.
+const DEFAULT_DELIMITER: u8 = b',';
+/// The quote character `CsvPartialCacheBuilder` assumes by default.
+const DEFAULT_QUOTE: u8 = b'"';
+
+impl<T> Default for CsvPartialCacheBuilder<T> {
+    fn default() -> Self {
+        Self {
+            delimiter: DEFAULT_DELIMITER,
+            quote: DEFAULT_QUOTE,
+            has_headers: true,
+            comment: None,
+            flexible: false,
+            quoting: true,
+            _item: PhantomData,
+        }
+    }
+}
+
+impl<T> CsvPartialCacheBuilder<T> {
+    /// Creates a new builder with the default options: comma-delimited,
+    /// `"`-quoted, with a header row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field delimiter. Defaults to `,`.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the quote character. Defaults to `"`.
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets whether the first record is a header row to skip. Defaults to
+    /// `true`.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Sets a comment prefix byte; records starting with it are ignored.
+    /// Defaults to `None`.
+    pub fn comment(mut self, comment: Option<u8>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// Allows records with a varying number of fields. Defaults to `false`.
+    pub fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+
+    /// Enables or disables RFC-4180 quote handling. Defaults to `true`.
+    ///
+    /// Disabling it switches to the faster [`LineOffset`] fast path, which
+    /// splits the file on `\n` and will misparse quoted fields that embed
+    /// the delimiter or a newline. That path also doesn't check that records
+    /// have the same number of fields, whatever [`Self::flexible`] is set to.
+    pub fn quoting(mut self, quoting: bool) -> Self {
+        self.quoting = quoting;
+        self
+    }
+}
+
+impl<T> CsvPartialCacheBuilder<T>
 where
     T: FromLineOffset,
 {
-    /// Creates a new `CsvPartialCache` from a path.
-    pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+    /// Indexes `path` with the configured options, building a
+    /// [`CsvPartialCache`].
+    pub fn build(self, path: impl Into<PathBuf>) -> Result<CsvPartialCache<T>> {
         let path = path.into();
+        let (items, schema) = if self.quoting {
+            self.build_with_csv_reader(&path)?
+        } else {
+            self.build_with_line_offset(&path)?
+        };
+        Ok(CsvPartialCache {
+            path,
+            items,
+            indices: Vec::new(),
+            schema,
+            quote: self.quoting.then_some(self.quote),
+        })
+    }
+
+    /// Fast path: splits the file on `\n`, ignoring quoting rules.
+    fn build_with_line_offset(&self, path: &Path) -> Result<(Box<[T]>, Schema)> {
         let mut items = Vec::new();
-        let mut index = LineOffset::from_path(&path)?;
-        index.next(); // skip the header
+        let mut index = LineOffset::from_path(path)?;
+        let is_comment =
+            |line: &str| matches!(self.comment, Some(c) if line.as_bytes().first() == Some(&c));
+        let mut schema = Schema::default();
+        if self.has_headers {
+            for row in index.by_ref() {
+                let (header, _offset) = row?;
+                if !is_comment(&header) {
+                    schema = Schema::parse_line(&header, self.delimiter);
+                    break;
+                }
+            }
+        }
         for row in index {
             let (line, offset) = row?;
+            if is_comment(&line) {
+                continue;
+            }
+            if let Some((column, expected)) = schema.validate_line(&line, self.delimiter) {
+                return Err(Error::SchemaMismatch {
+                    file: path.into(),
+                    offset: offset.into(),
+                    column,
+                    expected,
+                    line,
+                });
+            }
             items.push(T::from_line_offset(&line, offset)?);
         }
-        let items = items.into_boxed_slice();
-        Ok(Self { path, items })
+        Ok((items.into_boxed_slice(), schema))
+    }
+
+    /// Quote-aware path: drives a `csv::Reader` to find real record
+    /// boundaries, then re-reads each record's raw bytes from the file so
+    /// that `T::from_line_offset` still sees the original text.
+    fn build_with_csv_reader(&self, path: &Path) -> Result<(Box<[T]>, Schema)> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .has_headers(self.has_headers)
+            .flexible(self.flexible)
+            .comment(self.comment)
+            .from_path(path)
+            .map_err(Error::Csv)?;
+        let schema = if self.has_headers {
+            Schema::parse(reader.headers().map_err(Error::Csv)?)
+        } else {
+            Schema::default()
+        };
+
+        let file = File::open(path).map_err(|e| Error::OpenFile(e, path.into()))?;
+        let mut raw_reader = BufReader::new(file);
+
+        let mut offsets = Vec::new();
+        let mut record = csv::ByteRecord::new();
+        loop {
+            let offset = reader.position().byte();
+            if !reader.read_byte_record(&mut record).map_err(Error::Csv)? {
+                break;
+            }
+            if let Some((column, expected)) = schema.validate_byte_record(&record) {
+                // `record.as_slice()` concatenates fields with no delimiters
+                // between them, so re-read the record's own raw bytes for an
+                // accurate `line` instead of a misleading, un-reconstructable
+                // one (e.g. `["1", "a,b"]` would otherwise report `"1a,b"`).
+                let (_, line) = read_raw_record(&mut raw_reader, offset, Some(self.quote), path)?;
+                return Err(Error::SchemaMismatch {
+                    file: path.into(),
+                    offset,
+                    column,
+                    expected,
+                    line,
+                });
+            }
+            offsets.push(offset);
+        }
+
+        let mut items = Vec::with_capacity(offsets.len());
+        for (index, &start) in offsets.iter().enumerate() {
+            let (start, line) = read_raw_record(&mut raw_reader, start, Some(self.quote), path)?;
+            let offset = T::Offset::try_from(start).map_err(|_| {
+                Error::IntoOffset(start, index, path.to_string_lossy().into_owned())
+            })?;
+            items.push(T::from_line_offset(&line, offset)?);
+        }
+        Ok((items.into_boxed_slice(), schema))
+    }
+}
+
+/// A column's declared type, parsed from a `name:type` header cell (e.g.
+/// `price:number`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// `:string`
+    String,
+    /// `:number`
+    Number,
+    /// `:boolean`
+    Boolean,
+}
+
+impl ColumnType {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "string" => Some(Self::String),
+            "number" => Some(Self::Number),
+            "boolean" => Some(Self::Boolean),
+            _ => None,
+        }
+    }
+
+    fn matches(self, value: &str) -> bool {
+        match self {
+            Self::String => true,
+            Self::Number => value.parse::<f64>().is_ok(),
+            Self::Boolean => matches!(value, "true" | "false"),
+        }
+    }
+}
+
+/// A header cell parsed into a column name and an optional declared type
+/// (the `:type` suffix, e.g. `active:boolean`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    /// The part of the header cell before `:`.
+    pub name: String,
+    /// The column's declared type, if the header cell had a recognized
+    /// `:type` suffix.
+    pub ty: Option<ColumnType>,
+}
+
+impl Column {
+    /// Only strips the `:type` suffix when it parses to a known
+    /// [`ColumnType`]; an unrecognized suffix (e.g. `ratio:pct`, or a
+    /// colon that isn't a type annotation at all, like `http://host`) is
+    /// kept as part of the name rather than silently dropped.
+    fn parse(cell: &str) -> Self {
+        if let Some((name, ty)) = cell.split_once(':') {
+            if let Some(ty) = ColumnType::parse(ty) {
+                return Self {
+                    name: name.to_owned(),
+                    ty: Some(ty),
+                };
+            }
+        }
+        Self {
+            name: cell.to_owned(),
+            ty: None,
+        }
+    }
+}
+
+/// The header row parsed into one [`Column`] per field, in file order.
+///
+/// Empty (no columns) when the cache was built with `has_headers(false)`.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    /// The parsed columns, in header order.
+    pub columns: Vec<Column>,
+}
+
+impl Schema {
+    fn parse(header: &csv::StringRecord) -> Self {
+        Self {
+            columns: header.iter().map(Column::parse).collect(),
+        }
+    }
+
+    fn parse_line(header: &str, delimiter: u8) -> Self {
+        let delimiter = delimiter as char;
+        Self {
+            columns: header.split(delimiter).map(Column::parse).collect(),
+        }
+    }
+
+    /// Returns the first column whose declared type doesn't match its value
+    /// in `record`, if any.
+    fn validate_byte_record(&self, record: &csv::ByteRecord) -> Option<(String, ColumnType)> {
+        self.columns
+            .iter()
+            .zip(record.iter())
+            .find_map(|(column, value)| {
+                let ty = column.ty?;
+                let value = String::from_utf8_lossy(value);
+                (!ty.matches(&value)).then(|| (column.name.clone(), ty))
+            })
+    }
+
+    /// Returns the first column whose declared type doesn't match its value
+    /// in `line`, naively split on `delimiter` (no quote handling).
+    fn validate_line(&self, line: &str, delimiter: u8) -> Option<(String, ColumnType)> {
+        let delimiter = delimiter as char;
+        self.columns
+            .iter()
+            .zip(line.split(delimiter))
+            .find_map(|(column, value)| {
+                let ty = column.ty?;
+                (!ty.matches(value)).then(|| (column.name.clone(), ty))
+            })
+    }
+}
+
+impl<T> CsvPartialCache<T>
+where
+    T: FromLineOffset,
+{
+    /// Creates a new `CsvPartialCache` from a path, using the default parse
+    /// options (comma-delimited, `"`-quoted, with a header row).
+    ///
+    /// Equivalent to `CsvPartialCache::builder().build(path)`.
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+        Self::builder().build(path)
+    }
+
+    /// Returns a [`CsvPartialCacheBuilder`] for configuring delimiter, quote,
+    /// header and comment handling before indexing a file.
+    pub fn builder() -> CsvPartialCacheBuilder<T> {
+        CsvPartialCacheBuilder::new()
     }
 
     /// Finds an item in the cache by a key.
+    ///
+    /// Assumes `items` is globally sorted by the key `f` extracts; if it
+    /// isn't, this silently returns the wrong item (or `None`). When that
+    /// invariant doesn't hold, or lookups by more than one key are needed,
+    /// build an explicit index with [`Self::build_index`] and look it up
+    /// with [`Self::find_by`] instead.
     pub fn find<B, F>(&self, b: &B, f: F) -> Option<&T>
     where
         F: FnMut(&T) -> B,
@@ -214,11 +613,45 @@ where
             .ok()
     }
 
-    /// Returns a CSV line by its ID.
+    /// Builds a secondary index over `items`, sorted by a key extracted from
+    /// each item, and returns an id to pass to [`Self::find_by`].
+    ///
+    /// Unlike [`Self::find`], this doesn't require `items` itself to be
+    /// sorted by `extract`'s key, and several indices (e.g. by id, name,
+    /// code) can coexist on the same cache.
+    pub fn build_index<K>(&mut self, mut extract: impl FnMut(&T) -> K) -> usize
+    where
+        K: Ord + 'static,
+    {
+        let mut entries: Vec<(K, usize)> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (extract(item), i))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        self.indices.push(Box::new(KeyIndex {
+            entries: entries.into_boxed_slice(),
+        }));
+        self.indices.len() - 1
+    }
+
+    /// Looks up an item via the secondary index `index_id` (as returned by
+    /// [`Self::build_index`]), binary-searching that index rather than
+    /// relying on `items` being sorted by `key`.
+    pub fn find_by<K: Ord + 'static>(&self, index_id: usize, key: &K) -> Option<&T> {
+        self.indices
+            .get(index_id)?
+            .find(key)
+            .and_then(|i| self.items.get(i))
+    }
+
+    /// Returns a CSV record's raw text by its ID, reading up to its own
+    /// quote-aware boundary so a field that embeds a newline is read whole.
     async fn details_line(&self, row: &T) -> Result<String> {
         use tokio::{
             fs::File,
-            io::{AsyncBufReadExt, AsyncSeekExt, BufReader},
+            io::{AsyncSeekExt, BufReader},
         };
 
         let mut f = File::open(&self.path)
@@ -229,10 +662,13 @@ where
             .await
             .map_err(|e| Error::Seek(e, self.path.to_owned()))?;
         let mut buf = BufReader::new(f);
-        let mut line = String::new();
-        buf.read_line(&mut line)
+        let bytes = read_record_async(&mut buf, self.quote)
             .await
             .map_err(|e| Error::ReadLineOffset(e, offset, self.path.to_owned()))?;
+        let mut line = String::from_utf8_lossy(&bytes).into_owned();
+        while matches!(line.as_bytes().last(), Some(b'\n' | b'\r')) {
+            line.pop();
+        }
         Ok(line)
     }
 
@@ -246,6 +682,245 @@ where
             line,
         })
     }
+
+    /// Reads the full records for `rows`, deserialized into `D`, in a single
+    /// pass over the file instead of one open/seek/read per row.
+    ///
+    /// Requests are sorted by offset before reading so the seeks move
+    /// forward through the file, then results are remapped back to match
+    /// the order of `rows`.
+    pub async fn full_records<D: DeserializeOwned>(&self, rows: &[&T]) -> Result<Vec<D>> {
+        use tokio::{
+            fs::File,
+            io::{AsyncSeekExt, BufReader},
+        };
+
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by_key(|&i| rows[i].offset().into());
+
+        let f = File::open(&self.path)
+            .await
+            .map_err(|e| Error::OpenFile(e, self.path.to_owned()))?;
+        let mut buf = BufReader::new(f);
+
+        let mut records: Vec<Option<D>> = (0..rows.len()).map(|_| None).collect();
+        for i in order {
+            let offset = rows[i].offset().into();
+            buf.seek(SeekFrom::Start(offset))
+                .await
+                .map_err(|e| Error::Seek(e, self.path.to_owned()))?;
+            let bytes = read_record_async(&mut buf, self.quote)
+                .await
+                .map_err(|e| Error::ReadLineOffset(e, offset, self.path.to_owned()))?;
+            let mut line = String::from_utf8_lossy(&bytes).into_owned();
+            while matches!(line.as_bytes().last(), Some(b'\n' | b'\r')) {
+                line.pop();
+            }
+            records[i] =
+                Some(
+                    csv_line::from_str::<D>(&line).map_err(|e| Error::DecodeDetails {
+                        source: e,
+                        file: self.path.clone(),
+                        offset,
+                        line,
+                    })?,
+                );
+        }
+        Ok(records
+            .into_iter()
+            .map(|r| r.expect("every index was visited"))
+            .collect())
+    }
+}
+
+// #[cfg(feature = "mmap")]
+//
+// Commented out, not `#[cfg(feature = "mmap")]`, for the same reason as the
+// `cache` placeholder below: this crate has no `Cargo.toml` yet to declare
+// the feature, so there's nothing to gate on. `memmap2` is pulled in
+// unconditionally until one exists; flip this (and add the feature + an
+// optional `memmap2` dependency) once a manifest lands.
+/// An index that memory-maps its CSV file once, serving record bytes by
+/// slicing the map instead of opening/seeking/reading on every lookup.
+///
+/// # Safety contract
+/// The mapped file must not be truncated while a `MmapCsvPartialCache` over
+/// it is alive. Doing so is undefined behavior, per [`memmap2::Mmap::map`].
+/// Appending to the file, or leaving it untouched, is fine.
+#[derive(Debug)]
+pub struct MmapCsvPartialCache<T> {
+    cache: CsvPartialCache<T>,
+    mmap: memmap2::Mmap,
+}
+
+impl<T> std::ops::Deref for MmapCsvPartialCache<T> {
+    type Target = CsvPartialCache<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cache
+    }
+}
+
+impl<T> MmapCsvPartialCache<T>
+where
+    T: FromLineOffset,
+{
+    /// Indexes `path` (same as [`CsvPartialCache::new`]) and memory-maps the
+    /// file so lookups can avoid per-call syscalls.
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+        let cache = CsvPartialCache::new(path)?;
+        let mmap = map_file(&cache.path)?;
+        Ok(Self { cache, mmap })
+    }
+
+    /// Synchronously deserializes the full record for `row` into `D` by
+    /// slicing it directly out of the memory-mapped file, with no open,
+    /// seek, or read syscall.
+    pub fn full_record_mmap<D: DeserializeOwned>(&self, row: &T) -> Result<D> {
+        let offset: u64 = row.offset().into();
+        let start = offset as usize;
+        let rest = &self.mmap[start..];
+        let mut end = record_end(rest, self.cache.quote);
+        if end > 0 && rest[end - 1] == b'\r' {
+            end -= 1;
+        }
+        let line = String::from_utf8_lossy(&rest[..end]);
+        csv_line::from_str::<D>(&line).map_err(|e| Error::DecodeDetails {
+            source: e,
+            file: self.cache.path.clone(),
+            offset,
+            line: line.into_owned(),
+        })
+    }
+}
+
+/// Memory-maps `path` for read-only access.
+fn map_file(path: &Path) -> Result<memmap2::Mmap> {
+    let file = File::open(path).map_err(|e| Error::OpenFile(e, path.into()))?;
+    // SAFETY: caller's responsibility per `MmapCsvPartialCache`'s safety contract above.
+    unsafe { memmap2::Mmap::map(&file) }.map_err(|e| Error::Mmap(e, path.into()))
+}
+
+/// Quote-aware record-boundary scanner, shared by every path that reads a
+/// record's raw bytes: the mmap slice, the plain `std::io` re-read in
+/// [`CsvPartialCacheBuilder::build_with_csv_reader`], and the `tokio`
+/// re-reads in `full_record`/`full_records`. Tracks quote state across
+/// calls so a field's embedded `\n` is never mistaken for a record boundary,
+/// even when the bytes arrive in several chunks.
+#[derive(Debug, Default)]
+struct RecordScanner {
+    in_quotes: bool,
+}
+
+impl RecordScanner {
+    /// Scans `chunk` for the record-ending `\n`, returning its index within
+    /// `chunk` if found. Quote state carries over between calls. `quote ==
+    /// None` means quoting is disabled (e.g. `quoting(false)` was used at
+    /// index time): every `\n` ends the record, matching [`LineOffset`].
+    fn scan(&mut self, chunk: &[u8], quote: Option<u8>) -> Option<usize> {
+        for (i, &b) in chunk.iter().enumerate() {
+            if Some(b) == quote {
+                self.in_quotes = !self.in_quotes;
+            } else if b == b'\n' && !self.in_quotes {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+/// Scans `bytes` (already fully in memory, e.g. an mmap slice) for the end
+/// of a single CSV record. Returns `bytes.len()` if no unquoted newline is
+/// found (the last record, possibly without a trailing newline).
+fn record_end(bytes: &[u8], quote: Option<u8>) -> usize {
+    RecordScanner::default()
+        .scan(bytes, quote)
+        .unwrap_or(bytes.len())
+}
+
+/// Reads one quote-aware CSV record from `reader`, starting at its current
+/// position, including its trailing `\n` if present. Reads its own content
+/// rather than trusting a neighboring record's offset, so bytes skipped
+/// between records (e.g. comment lines) are never pulled in.
+fn read_record(reader: &mut impl BufRead, quote: Option<u8>) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut scanner = RecordScanner::default();
+    loop {
+        let chunk_start = bytes.len();
+        if reader.read_until(b'\n', &mut bytes)? == 0 {
+            break;
+        }
+        if let Some(end) = scanner.scan(&bytes[chunk_start..], quote) {
+            bytes.truncate(chunk_start + end + 1);
+            break;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Seeks `reader` to `start` and reads one raw CSV record's text, for
+/// re-reading a record indexed by [`CsvPartialCacheBuilder::build_with_csv_reader`].
+///
+/// `csv::Reader::position().byte()` can land one byte short of a record's
+/// true start: on `\r\n`-terminated input, the csv core consumes the
+/// previous record's `\r` as its terminator but defers consuming the `\n`
+/// until the next read, so `position()` reported just before that next
+/// read still points at the leftover `\n`. Skipping any leading `\r`/`\n`
+/// bytes (which can never legitimately start a record) corrects for this,
+/// and the corrected offset is returned so it can be stored instead of the
+/// raw one, keeping later `full_record`/`full_records`/`full_record_mmap`
+/// re-reads in sync.
+fn read_raw_record(
+    reader: &mut BufReader<File>,
+    start: u64,
+    quote: Option<u8>,
+    path: &Path,
+) -> Result<(u64, String)> {
+    reader
+        .seek(SeekFrom::Start(start))
+        .map_err(|e| Error::Seek(e, path.into()))?;
+    let mut start = start;
+    loop {
+        let buf = reader
+            .fill_buf()
+            .map_err(|e| Error::ReadRecord(e, start, path.into()))?;
+        match buf.first() {
+            Some(b'\r' | b'\n') => {
+                reader.consume(1);
+                start += 1;
+            }
+            _ => break,
+        }
+    }
+    let bytes = read_record(reader, quote).map_err(|e| Error::ReadRecord(e, start, path.into()))?;
+    let mut line = String::from_utf8_lossy(&bytes).into_owned();
+    while matches!(line.as_bytes().last(), Some(b'\n' | b'\r')) {
+        line.pop();
+    }
+    Ok((start, line))
+}
+
+/// The `tokio` counterpart of [`read_record`], used by the async
+/// `full_record`/`full_records` re-reads.
+async fn read_record_async<R>(reader: &mut R, quote: Option<u8>) -> io::Result<Vec<u8>>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::AsyncBufReadExt;
+
+    let mut bytes = Vec::new();
+    let mut scanner = RecordScanner::default();
+    loop {
+        let chunk_start = bytes.len();
+        if reader.read_until(b'\n', &mut bytes).await? == 0 {
+            break;
+        }
+        if let Some(end) = scanner.scan(&bytes[chunk_start..], quote) {
+            bytes.truncate(chunk_start + end + 1);
+            break;
+        }
+    }
+    Ok(bytes)
 }
 
 // #[cfg(feature = "cache")]
@@ -263,9 +938,13 @@ where
             index
         } else {
             let items = Self::items_from_cache(cache_path)?;
+            let schema = read_header_schema(&csv_path, DEFAULT_DELIMITER)?;
             Self {
                 path: csv_path,
                 items,
+                indices: Vec::new(),
+                schema,
+                quote: Some(DEFAULT_QUOTE),
             }
         })
     }
@@ -283,6 +962,144 @@ where
             serde_json::from_reader(reader).map_err(|e| Error::ReadCache(e, cache_path.into()))?;
         Ok(items.into_boxed_slice())
     }
+
+    /// Creates an index using an intermediate binary cache file, per
+    /// [`CacheOptions`]. The cache is a compact `bincode` encoding of the
+    /// items, optionally `zstd`-compressed, prefixed with a small header
+    /// carrying [`CACHE_FORMAT_VERSION`] and a content digest of the source
+    /// CSV.
+    ///
+    /// The cache is trusted as-is if the CSV's mtime hasn't advanced past
+    /// the cache's; `options.verify_hash` additionally re-hashes the CSV to
+    /// catch copies, restores, or touches that leave mtime looking fresh
+    /// while the content has changed. Any mismatch in format version or
+    /// digest falls back to a full rebuild from the CSV.
+    pub fn from_binary_cache(
+        csv_path: impl Into<PathBuf>,
+        cache_path: impl AsRef<Path>,
+        options: CacheOptions,
+    ) -> Result<Self> {
+        let csv_path = csv_path.into();
+        let cache_path = cache_path.as_ref();
+        if !is_cache_expired(&csv_path, cache_path)? {
+            if let Some(items) = Self::items_from_binary_cache(&csv_path, cache_path, &options)? {
+                let schema = read_header_schema(&csv_path, DEFAULT_DELIMITER)?;
+                return Ok(Self {
+                    path: csv_path,
+                    items,
+                    indices: Vec::new(),
+                    schema,
+                    quote: Some(DEFAULT_QUOTE),
+                });
+            }
+        }
+        let index = Self::new(csv_path)?;
+        Self::items_to_binary_cache(&index.items, &index.path, cache_path, &options)?;
+        Ok(index)
+    }
+
+    fn items_to_binary_cache(
+        items: &[T],
+        csv_path: &Path,
+        cache_path: &Path,
+        options: &CacheOptions,
+    ) -> Result<()> {
+        let (csv_len, csv_hash) = csv_digest(csv_path)?;
+        let payload = bincode::serialize(&(csv_len, csv_hash, items))
+            .map_err(|e| Error::EncodeCache(e, cache_path.into()))?;
+        let payload = if options.compress {
+            zstd::stream::encode_all(io::Cursor::new(payload), 0)
+                .map_err(|e| Error::Zstd(e, cache_path.into()))?
+        } else {
+            payload
+        };
+
+        let mut bytes = Vec::with_capacity(CACHE_HEADER_LEN + payload.len());
+        bytes.extend_from_slice(&CACHE_MAGIC);
+        bytes.extend_from_slice(&CACHE_FORMAT_VERSION.to_le_bytes());
+        bytes.push(options.compress as u8);
+        bytes.extend_from_slice(&payload);
+
+        fs::write(cache_path, bytes).map_err(|e| Error::CreateFile(e, cache_path.into()))?;
+        Ok(())
+    }
+
+    /// Returns `Ok(None)` when the cache is missing, of an unknown format, or
+    /// (with `verify_hash`) stale relative to the CSV's content, so the
+    /// caller can fall back to a full rebuild.
+    fn items_from_binary_cache(
+        csv_path: &Path,
+        cache_path: &Path,
+        options: &CacheOptions,
+    ) -> Result<Option<Box<[T]>>> {
+        let bytes = match fs::read(cache_path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::OpenFile(e, cache_path.into())),
+        };
+        if bytes.len() < CACHE_HEADER_LEN || bytes[..4] != CACHE_MAGIC {
+            return Ok(None);
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != CACHE_FORMAT_VERSION {
+            return Ok(None);
+        }
+        let compressed = bytes[6] != 0;
+        let payload = &bytes[CACHE_HEADER_LEN..];
+        let payload = if compressed {
+            zstd::stream::decode_all(payload).map_err(|e| Error::Zstd(e, cache_path.into()))?
+        } else {
+            payload.to_vec()
+        };
+        let (csv_len, csv_hash, items): (u64, [u8; 32], Vec<T>) =
+            bincode::deserialize(&payload).map_err(|e| Error::DecodeCache(e, cache_path.into()))?;
+
+        if options.verify_hash {
+            let (actual_len, actual_hash) = csv_digest(csv_path)?;
+            if actual_len != csv_len || actual_hash != csv_hash {
+                return Ok(None);
+            }
+        }
+        Ok(Some(items.into_boxed_slice()))
+    }
+}
+
+/// Options controlling how [`CsvPartialCache::from_binary_cache`] writes and
+/// validates the on-disk cache.
+#[derive(Debug, Clone, Copy)]
+pub struct CacheOptions {
+    /// Compress the cached bytes with `zstd`.
+    pub compress: bool,
+    /// Re-hash the source CSV's content on load rather than trusting the
+    /// mtime pre-check alone.
+    pub verify_hash: bool,
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            compress: true,
+            verify_hash: true,
+        }
+    }
+}
+
+/// The on-disk binary cache format version. Bump this whenever the header or
+/// payload layout changes so old caches are rejected instead of misread.
+const CACHE_FORMAT_VERSION: u16 = 1;
+
+/// Magic bytes identifying a binary cache file.
+const CACHE_MAGIC: [u8; 4] = *b"CPC\0";
+
+/// `CACHE_MAGIC` (4 bytes) + format version (2 bytes) + compressed flag (1 byte).
+const CACHE_HEADER_LEN: usize = 7;
+
+/// Returns the source CSV's length and a content digest, used to detect
+/// changes the mtime-based pre-check might miss.
+fn csv_digest(csv_path: &Path) -> Result<(u64, [u8; 32])> {
+    let bytes = fs::read(csv_path).map_err(|e| Error::OpenFile(e, csv_path.into()))?;
+    let hash = blake3::hash(&bytes);
+    Ok((bytes.len() as u64, *hash.as_bytes()))
 }
 
 /// Checks if the cache is expired by comparing the modification times of the
@@ -302,6 +1119,18 @@ fn file_modified_at(path: &Path) -> Result<SystemTime> {
         .map_err(|e| Error::GetFileModified(e, path.into()))
 }
 
+/// Parses just the CSV's header row into a [`Schema`], for cache-hit paths
+/// that otherwise skip reading the file. Keeps `schema` consistent whether
+/// the cache was warm or the index was freshly built, instead of depending
+/// on which branch ran.
+fn read_header_schema(csv_path: &Path, delimiter: u8) -> Result<Schema> {
+    let file = File::open(csv_path).map_err(|e| Error::OpenFile(e, csv_path.into()))?;
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .from_reader(file);
+    Ok(Schema::parse(reader.headers().map_err(Error::Csv)?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,4 +1151,240 @@ mod tests {
         assert_eq!(items.next().unwrap().unwrap(), (line1, 0));
         assert!(matches!(items.next().unwrap(), Err(Error::IntoOffset(..))));
     }
+
+    /// A minimal `id,name` row used to exercise `CsvPartialCache` without a
+    /// real caller-provided item type.
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Row {
+        id: u64,
+        name: String,
+        offset: u64,
+    }
+
+    impl FromLineOffset for Row {
+        type Offset = u64;
+
+        fn offset(&self) -> u64 {
+            self.offset
+        }
+
+        fn from_line_offset(line: &str, offset: u64) -> Result<Self> {
+            let mut parts = line.splitn(2, ',');
+            let id = parts.next().unwrap_or_default().parse().unwrap_or(0);
+            let name = parts.next().unwrap_or_default().to_owned();
+            Ok(Self { id, name, offset })
+        }
+    }
+
+    /// A path under the system temp dir, unique to this process and test.
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "csv_partial_cache_test_{}_{name}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn binary_cache_round_trip() {
+        let csv_path = temp_path("binary_cache_round_trip.csv");
+        let cache_path = temp_path("binary_cache_round_trip.cache");
+        fs::write(&csv_path, "id,name\n1,alice\n2,bob\n").unwrap();
+        let _ = fs::remove_file(&cache_path);
+
+        let options = CacheOptions::default();
+        let index = CsvPartialCache::<Row>::from_binary_cache(&csv_path, &cache_path, options)
+            .expect("cold cache builds from the csv");
+        assert_eq!(index.items.len(), 2);
+        assert!(cache_path.exists());
+
+        let reloaded = CsvPartialCache::<Row>::from_binary_cache(&csv_path, &cache_path, options)
+            .expect("warm cache loads from disk");
+        assert_eq!(reloaded.items, index.items);
+
+        fs::remove_file(&csv_path).unwrap();
+        fs::remove_file(&cache_path).unwrap();
+    }
+
+    #[test]
+    fn binary_cache_version_mismatch_is_ignored() {
+        let csv_path = temp_path("binary_cache_version_mismatch.csv");
+        let cache_path = temp_path("binary_cache_version_mismatch.cache");
+        fs::write(&csv_path, "id,name\n1,alice\n").unwrap();
+
+        let mut bytes = CACHE_MAGIC.to_vec();
+        bytes.extend_from_slice(&(CACHE_FORMAT_VERSION + 1).to_le_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(b"payload from a future, incompatible format");
+        fs::write(&cache_path, &bytes).unwrap();
+
+        let options = CacheOptions::default();
+        let items =
+            CsvPartialCache::<Row>::items_from_binary_cache(&csv_path, &cache_path, &options)
+                .expect("an unknown version is treated as a miss, not an error");
+        assert!(items.is_none());
+
+        fs::remove_file(&csv_path).unwrap();
+        fs::remove_file(&cache_path).unwrap();
+    }
+
+    #[test]
+    fn binary_cache_hash_mismatch_is_ignored() {
+        let csv_path = temp_path("binary_cache_hash_mismatch.csv");
+        let cache_path = temp_path("binary_cache_hash_mismatch.cache");
+        fs::write(&csv_path, "id,name\n1,alice\n").unwrap();
+
+        let options = CacheOptions::default();
+        CsvPartialCache::<Row>::items_to_binary_cache(
+            &[Row {
+                id: 1,
+                name: "alice".into(),
+                offset: 8,
+            }],
+            &csv_path,
+            &cache_path,
+            &options,
+        )
+        .unwrap();
+
+        // The csv changes after the cache was written...
+        fs::write(&csv_path, "id,name\n1,alice\n2,bob\n").unwrap();
+
+        let items =
+            CsvPartialCache::<Row>::items_from_binary_cache(&csv_path, &cache_path, &options)
+                .expect("a content hash mismatch is treated as a miss, not an error");
+        assert!(items.is_none());
+
+        fs::remove_file(&csv_path).unwrap();
+        fs::remove_file(&cache_path).unwrap();
+    }
+
+    #[test]
+    fn build_index_and_find_by() {
+        let csv_path = temp_path("build_index_and_find_by.csv");
+        fs::write(&csv_path, "id,name\n1,alice\n2,bob\n3,carol\n").unwrap();
+
+        let mut index = CsvPartialCache::<Row>::new(&csv_path).unwrap();
+        let by_name = index.build_index(|row| row.name.clone());
+
+        assert_eq!(index.find_by(by_name, &"bob".to_owned()).unwrap().id, 2);
+        assert!(index.find_by(by_name, &"dave".to_owned()).is_none());
+        // A key of the wrong type for this index must return `None`, not panic.
+        assert!(index.find_by(by_name, &7u64).is_none());
+
+        fs::remove_file(&csv_path).unwrap();
+    }
+
+    #[test]
+    fn column_parse_keeps_unrecognized_suffix() {
+        assert_eq!(
+            Column::parse("price:number"),
+            Column {
+                name: "price".into(),
+                ty: Some(ColumnType::Number),
+            }
+        );
+        assert_eq!(
+            Column::parse("ratio:pct"),
+            Column {
+                name: "ratio:pct".into(),
+                ty: None,
+            }
+        );
+        assert_eq!(
+            Column::parse("http://host"),
+            Column {
+                name: "http://host".into(),
+                ty: None,
+            }
+        );
+    }
+
+    #[test]
+    fn schema_rejects_value_not_matching_declared_type() {
+        let csv_path = temp_path("schema_rejects_value_not_matching_declared_type.csv");
+        fs::write(&csv_path, "name,active:boolean\nalice,maybe\n").unwrap();
+
+        let err = CsvPartialCache::<Row>::builder()
+            .quoting(false)
+            .build(&csv_path)
+            .unwrap_err();
+        assert!(matches!(err, Error::SchemaMismatch { ref column, .. } if column == "active"));
+
+        fs::remove_file(&csv_path).unwrap();
+    }
+
+    #[test]
+    fn handles_crlf_line_endings() {
+        let csv_path = temp_path("handles_crlf_line_endings.csv");
+        fs::write(&csv_path, "id,name\r\n1,alice\r\n2,bob\r\n").unwrap();
+
+        let index = CsvPartialCache::<Row>::new(&csv_path).unwrap();
+        // `position().byte()` after a `\r\n`-terminated record points at the
+        // not-yet-consumed `\n`, one byte before the next record's true
+        // start (9, 18) — asserting the corrected offsets also pins down
+        // that the fields parsed out whole, not truncated to "".
+        assert_eq!(
+            *index.items,
+            [
+                Row {
+                    id: 1,
+                    name: "alice".into(),
+                    offset: 9
+                },
+                Row {
+                    id: 2,
+                    name: "bob".into(),
+                    offset: 18
+                },
+            ]
+        );
+
+        fs::remove_file(&csv_path).unwrap();
+    }
+
+    #[test]
+    fn record_end_respects_disabled_quoting() {
+        // A lone quote character with quoting enabled toggles into an
+        // unterminated quoted field, swallowing the rest of the bytes.
+        let bytes = b"5\"\nnext";
+        assert_eq!(record_end(bytes, Some(b'"')), bytes.len());
+        // With quoting disabled (`quote: None`), every `\n` ends the record
+        // regardless of quote characters in the field.
+        assert_eq!(record_end(bytes, None), 2);
+    }
+
+    #[test]
+    fn quoting_disabled_index_keeps_quote_none_for_fetches() {
+        let csv_path = temp_path("quoting_disabled_index_keeps_quote_none_for_fetches.csv");
+        fs::write(&csv_path, "id,note\n1,5\"\n2,bob\n").unwrap();
+
+        let index = CsvPartialCache::<Row>::builder()
+            .quoting(false)
+            .build(&csv_path)
+            .unwrap();
+        assert_eq!(index.quote, None);
+
+        fs::remove_file(&csv_path).unwrap();
+    }
+
+    #[test]
+    fn schema_mismatch_reconstructs_full_line_with_quoted_delimiter() {
+        let csv_path =
+            temp_path("schema_mismatch_reconstructs_full_line_with_quoted_delimiter.csv");
+        fs::write(&csv_path, "name,active:boolean\n\"a,b\",maybe\n").unwrap();
+
+        let err = CsvPartialCache::<Row>::new(&csv_path).unwrap_err();
+        match err {
+            Error::SchemaMismatch { column, line, .. } => {
+                assert_eq!(column, "active");
+                // Reconstructed from the record's own raw bytes, so the
+                // quoted delimiter survives instead of being swallowed by
+                // `ByteRecord::as_slice()`'s field concatenation.
+                assert_eq!(line, "\"a,b\",maybe");
+            }
+            other => panic!("expected SchemaMismatch, got {other:?}"),
+        }
+
+        fs::remove_file(&csv_path).unwrap();
+    }
 }